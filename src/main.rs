@@ -1,4 +1,7 @@
+use std::collections::{HashMap, VecDeque};
 use std::io::{Result, stdout};
+use std::path::PathBuf;
+use std::time::{Duration, Instant};
 
 use crossterm::{
     ExecutableCommand,
@@ -7,13 +10,79 @@ use crossterm::{
 };
 
 use ratatui::{
-    prelude::{Alignment, Constraint, CrosstermBackend, Direction, Layout, Terminal},
+    prelude::{Alignment, Constraint, CrosstermBackend, Direction, Layout, Rect, Terminal},
     style::{Color, Modifier, Style, Stylize},
+    symbols,
     text::{Line, Span},
-    widgets::{Block, Borders, Paragraph, Row, Table, TableState},
+    widgets::{
+        Axis, Block, Borders, Chart, Clear, Dataset, GraphType, Paragraph, Row, Table, TableState,
+    },
 };
 
-use sysinfo::{Disks, ProcessRefreshKind, ProcessesToUpdate, System};
+use clap::{Parser, ValueEnum};
+use sysinfo::{Components, Disks, Networks, Pid, ProcessRefreshKind, ProcessesToUpdate, System};
+
+// How long a `d` keypress stays "armed" waiting for the second `d` of `dd`.
+const DOUBLE_KEY_TIMEOUT: Duration = Duration::from_millis(500);
+
+// How many samples the history charts keep on screen at once.
+const HISTORY_CAPACITY: usize = 120;
+
+// Max CPU core rows per column before the per-core list wraps into another column.
+const MAX_CPU_ROWS: usize = 8;
+
+#[derive(Clone, Copy, PartialEq, Eq)]
+enum SortBy {
+    Cpu,
+    Memory,
+}
+
+#[derive(Clone, Copy, PartialEq, Eq, ValueEnum)]
+enum TempUnit {
+    C,
+    F,
+    K,
+}
+
+impl std::fmt::Display for TempUnit {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        let label = match self {
+            TempUnit::C => "C",
+            TempUnit::F => "F",
+            TempUnit::K => "K",
+        };
+        write!(f, "{label}")
+    }
+}
+
+/// A terminal system monitor.
+#[derive(Parser)]
+#[command(name = "sysmon-cli", about = "A terminal system monitor")]
+struct Config {
+    /// Refresh interval in milliseconds
+    #[arg(long, default_value_t = 200)]
+    rate: u64,
+
+    /// Number of processes shown in the Processes table
+    #[arg(long, default_value_t = 20)]
+    top: usize,
+
+    /// Temperature unit to display readings in
+    #[arg(long = "temp-unit", value_enum, default_value_t = TempUnit::C)]
+    temp_unit: TempUnit,
+
+    /// Show a single averaged CPU bar instead of per-core bars
+    #[arg(long)]
+    avg_cpu: bool,
+
+    /// Input poll interval in milliseconds
+    #[arg(long = "poll-ms", default_value_t = 16)]
+    poll_ms: u64,
+
+    /// Number of disks shown in the Disk Usage panel
+    #[arg(long, default_value_t = 3)]
+    disks_shown: usize,
+}
 
 // System struct to hold system information
 struct App {
@@ -21,19 +90,59 @@ struct App {
     processes_state: TableState,
     tick_count: u64,       // Add a counter to track refresh cycles
     disks: sysinfo::Disks, // Add disks to track disk usage
+    selected_pid: Option<Pid>,
+    sort_by: SortBy,
+    sort_ascending: bool,
+    last_key: Option<(KeyCode, Instant)>,
+    pending_kill: Option<(Pid, String)>,
+    show_help: bool,
+    is_frozen: bool,
+    config: Config,
+    components: Components,
+    networks: Networks,
+    started_at: Instant,
+    last_sample_at: Instant,
+    cpu_history: VecDeque<(f64, f64)>,
+    memory_history: VecDeque<(f64, f64)>,
+    net_rx_history: VecDeque<(f64, f64)>,
+    net_tx_history: VecDeque<(f64, f64)>,
+    disk_io_totals: HashMap<PathBuf, (u64, u64)>,
+    disk_io_rates: HashMap<PathBuf, (f64, f64)>,
 }
 
 impl App {
-    fn new() -> Self {
+    fn new(config: Config) -> Self {
+        let now = Instant::now();
         Self {
             system: System::new_all(),
             processes_state: TableState::default(),
             tick_count: 0,
             disks: sysinfo::Disks::new_with_refreshed_list(),
+            selected_pid: None,
+            sort_by: SortBy::Cpu,
+            sort_ascending: false,
+            last_key: None,
+            pending_kill: None,
+            show_help: false,
+            is_frozen: false,
+            config,
+            components: Components::new_with_refreshed_list(),
+            networks: Networks::new_with_refreshed_list(),
+            started_at: now,
+            last_sample_at: now,
+            cpu_history: VecDeque::with_capacity(HISTORY_CAPACITY),
+            memory_history: VecDeque::with_capacity(HISTORY_CAPACITY),
+            net_rx_history: VecDeque::with_capacity(HISTORY_CAPACITY),
+            net_tx_history: VecDeque::with_capacity(HISTORY_CAPACITY),
+            disk_io_totals: HashMap::new(),
+            disk_io_rates: HashMap::new(),
         }
     }
 
     fn on_tick(&mut self) {
+        if self.is_frozen {
+            return;
+        }
         self.tick_count += 1;
         self.system.refresh_cpu_all();
         self.system.refresh_memory();
@@ -42,7 +151,232 @@ impl App {
             true,
             ProcessRefreshKind::everything(),
         );
-        // Disk refresh is handled automatically
+        self.components.refresh(true);
+        self.networks.refresh(true);
+        self.disks.refresh(true);
+
+        let now = Instant::now();
+        let elapsed_secs = now.duration_since(self.started_at).as_secs_f64();
+        let interval_secs = now
+            .duration_since(self.last_sample_at)
+            .as_secs_f64()
+            .max(f64::EPSILON);
+        self.last_sample_at = now;
+
+        let cpu_usage = if self.system.cpus().is_empty() {
+            0.0
+        } else {
+            self.system
+                .cpus()
+                .iter()
+                .map(|cpu| cpu.cpu_usage() as f64)
+                .sum::<f64>()
+                / self.system.cpus().len() as f64
+        };
+        let memory_usage = if self.system.total_memory() == 0 {
+            0.0
+        } else {
+            self.system.used_memory() as f64 / self.system.total_memory() as f64 * 100.0
+        };
+        let (rx_bytes, tx_bytes) = self
+            .networks
+            .iter()
+            .fold((0u64, 0u64), |(rx, tx), (_, data)| {
+                (rx + data.received(), tx + data.transmitted())
+            });
+        let rx_rate = rx_bytes as f64 / interval_secs;
+        let tx_rate = tx_bytes as f64 / interval_secs;
+
+        Self::push_sample(&mut self.cpu_history, elapsed_secs, cpu_usage);
+        Self::push_sample(&mut self.memory_history, elapsed_secs, memory_usage);
+        Self::push_sample(&mut self.net_rx_history, elapsed_secs, rx_rate);
+        Self::push_sample(&mut self.net_tx_history, elapsed_secs, tx_rate);
+
+        // Diff each disk's cumulative read/written totals against the
+        // previous tick to get a rate; first-seen disks show 0 rather than
+        // a spike, and a total going backwards (hot-plug, counter reset)
+        // clamps to 0 instead of underflowing.
+        let mut disk_io_totals = HashMap::with_capacity(self.disks.len());
+        let mut disk_io_rates = HashMap::with_capacity(self.disks.len());
+        for disk in self.disks.iter() {
+            let mount_point = disk.mount_point().to_path_buf();
+            let usage = disk.usage();
+            let (prev_read, prev_written) = self
+                .disk_io_totals
+                .get(&mount_point)
+                .copied()
+                .unwrap_or((usage.total_read_bytes, usage.total_written_bytes));
+
+            let read_rate =
+                usage.total_read_bytes.saturating_sub(prev_read) as f64 / interval_secs;
+            let write_rate =
+                usage.total_written_bytes.saturating_sub(prev_written) as f64 / interval_secs;
+
+            disk_io_rates.insert(mount_point.clone(), (read_rate, write_rate));
+            disk_io_totals.insert(
+                mount_point,
+                (usage.total_read_bytes, usage.total_written_bytes),
+            );
+        }
+        self.disk_io_totals = disk_io_totals;
+        self.disk_io_rates = disk_io_rates;
+    }
+
+    fn push_sample(history: &mut VecDeque<(f64, f64)>, x: f64, y: f64) {
+        if history.len() == HISTORY_CAPACITY {
+            history.pop_front();
+        }
+        history.push_back((x, y));
+    }
+
+    // PIDs in the order the Processes table is currently displayed, so
+    // selection and rendering always agree on "row N".
+    fn sorted_pids(&self) -> Vec<Pid> {
+        let mut processes: Vec<_> = self.system.processes().iter().collect();
+        processes.sort_by(|a, b| {
+            let (a_val, b_val) = match self.sort_by {
+                SortBy::Cpu => (a.1.cpu_usage(), b.1.cpu_usage()),
+                SortBy::Memory => (a.1.memory() as f32, b.1.memory() as f32),
+            };
+            let ordering = a_val
+                .partial_cmp(&b_val)
+                .unwrap_or(std::cmp::Ordering::Equal);
+            if self.sort_ascending {
+                ordering
+            } else {
+                ordering.reverse()
+            }
+        });
+        processes.into_iter().map(|(pid, _)| *pid).collect()
+    }
+
+    fn select_next(&mut self) {
+        let pids = self.sorted_pids();
+        if pids.is_empty() {
+            return;
+        }
+        let idx = self
+            .selected_pid
+            .and_then(|pid| pids.iter().position(|p| *p == pid))
+            .unwrap_or(usize::MAX);
+        let next = idx.wrapping_add(1).min(pids.len() - 1);
+        self.selected_pid = Some(pids[next]);
+    }
+
+    fn select_previous(&mut self) {
+        let pids = self.sorted_pids();
+        if pids.is_empty() {
+            return;
+        }
+        let idx = self
+            .selected_pid
+            .and_then(|pid| pids.iter().position(|p| *p == pid))
+            .unwrap_or(0);
+        self.selected_pid = Some(pids[idx.saturating_sub(1)]);
+    }
+
+    // Pressing the active sort key again reverses order; switching sort
+    // columns resets to descending (the "most interesting first" default).
+    fn toggle_sort(&mut self, by: SortBy) {
+        if self.sort_by == by {
+            self.sort_ascending = !self.sort_ascending;
+        } else {
+            self.sort_by = by;
+            self.sort_ascending = false;
+        }
+    }
+
+    fn request_kill_selected(&mut self) {
+        if let Some(pid) = self.selected_pid {
+            if let Some(process) = self.system.process(pid) {
+                self.pending_kill = Some((pid, process.name().to_string_lossy().to_string()));
+            }
+        }
+    }
+
+    fn confirm_kill(&mut self) {
+        if let Some((pid, _)) = self.pending_kill.take() {
+            if let Some(process) = self.system.process(pid) {
+                process.kill();
+            }
+        }
+    }
+
+    fn cancel_kill(&mut self) {
+        self.pending_kill = None;
+    }
+}
+
+// A Rect centered within `area`, `percent_x` wide and `percent_y` tall.
+fn centered_rect(percent_x: u16, percent_y: u16, area: Rect) -> Rect {
+    let vertical = Layout::default()
+        .direction(Direction::Vertical)
+        .constraints([
+            Constraint::Percentage((100 - percent_y) / 2),
+            Constraint::Percentage(percent_y),
+            Constraint::Percentage((100 - percent_y) / 2),
+        ])
+        .split(area);
+
+    Layout::default()
+        .direction(Direction::Horizontal)
+        .constraints([
+            Constraint::Percentage((100 - percent_x) / 2),
+            Constraint::Percentage(percent_x),
+            Constraint::Percentage((100 - percent_x) / 2),
+        ])
+        .split(vertical[1])[1]
+}
+
+// A bordered 0-100% line chart over `data`. A closure can't express the
+// lifetime tying the returned `Chart<'a>` to `data: &'a [...]`, so this is a
+// plain fn with an explicit lifetime parameter instead.
+fn percent_chart<'a>(
+    title: &'a str,
+    data: &'a [(f64, f64)],
+    color: Color,
+    x_bounds: [f64; 2],
+) -> Chart<'a> {
+    let dataset = Dataset::default()
+        .name(title)
+        .marker(symbols::Marker::Braille)
+        .graph_type(GraphType::Line)
+        .style(Style::default().fg(color))
+        .data(data);
+
+    Chart::new(vec![dataset])
+        .block(Block::default().title(title).borders(Borders::ALL).fg(color))
+        .x_axis(Axis::default().bounds(x_bounds))
+        .y_axis(
+            Axis::default()
+                .bounds([0.0, 100.0])
+                .labels(vec![Span::raw("0"), Span::raw("50"), Span::raw("100")]),
+        )
+}
+
+// Converts a Celsius reading to `unit` and formats it with its suffix.
+fn format_temp(celsius: f32, unit: TempUnit) -> String {
+    match unit {
+        TempUnit::C => format!("{celsius:.1}°C"),
+        TempUnit::F => format!("{:.1}°F", celsius * 9.0 / 5.0 + 32.0),
+        TempUnit::K => format!("{:.1}K", celsius + 273.15),
+    }
+}
+
+// Formats a bytes-per-second rate with the largest unit that keeps it >= 1.
+fn format_rate(bytes_per_sec: f64) -> String {
+    const KB: f64 = 1024.0;
+    const MB: f64 = KB * 1024.0;
+    const GB: f64 = MB * 1024.0;
+
+    if bytes_per_sec >= GB {
+        format!("{:.1} GB/s", bytes_per_sec / GB)
+    } else if bytes_per_sec >= MB {
+        format!("{:.1} MB/s", bytes_per_sec / MB)
+    } else if bytes_per_sec >= KB {
+        format!("{:.1} KB/s", bytes_per_sec / KB)
+    } else {
+        format!("{bytes_per_sec:.0} B/s")
     }
 }
 
@@ -60,11 +394,13 @@ const ASCII_ART: &str = r#"
 /*  */
 
 fn main() -> Result<()> {
+    let config = Config::parse();
+
     stdout().execute(EnterAlternateScreen)?;
     enable_raw_mode()?;
     let mut terminal = Terminal::new(CrosstermBackend::new(stdout()))?;
 
-    let mut app = App::new();
+    let mut app = App::new(config);
 
     for _ in 0..3 {
         app.on_tick();
@@ -79,7 +415,11 @@ fn main() -> Result<()> {
         terminal.draw(|frame| {
             let main_chunk = Layout::default()
                 .direction(Direction::Vertical)
-                .constraints([Constraint::Percentage(50), Constraint::Percentage(50)])
+                .constraints([
+                    Constraint::Percentage(35),
+                    Constraint::Percentage(25),
+                    Constraint::Percentage(40),
+                ])
                 .split(frame.size());
 
             let top_chunk = Layout::default()
@@ -87,11 +427,20 @@ fn main() -> Result<()> {
                 .constraints([Constraint::Percentage(50), Constraint::Percentage(50)])
                 .split(main_chunk[0]);
 
+            let history_chunk = Layout::default()
+                .direction(Direction::Horizontal)
+                .constraints([
+                    Constraint::Percentage(34),
+                    Constraint::Percentage(33),
+                    Constraint::Percentage(33),
+                ])
+                .split(main_chunk[1]);
+
             let upper_chunk = Layout::default()
                 .direction(Direction::Vertical)
                 .constraints([
                     Constraint::Length(4),
-                    Constraint::Length(4),
+                    Constraint::Length(6),
                     Constraint::Min(0),
                 ]) // Memory, Disk, Logo
                 .split(top_chunk[0]);
@@ -173,58 +522,91 @@ fn main() -> Result<()> {
 
             /* Rendering the Disk Usage Widget */
 
-            let mut disk_lines = Vec::new();
+            let disk_widths = [
+                Constraint::Length(8),  // Disk
+                Constraint::Min(8),     // Mount
+                Constraint::Length(7),  // Used
+                Constraint::Length(7),  // Free
+                Constraint::Length(7),  // Total
+                Constraint::Length(11), // R/s
+                Constraint::Length(11), // W/s
+            ];
 
-            for disk in app.disks.iter().take(3) {
-                // Show first 3 disks
-                let total_space = disk.total_space();
-                let available_space = disk.available_space();
-                let used_space = total_space - available_space;
-                let usage_percent = if total_space > 0 {
-                    (used_space as f64 / total_space as f64) * 100.0
-                } else {
-                    0.0
-                };
+            let disk_rows: Vec<Row> = app
+                .disks
+                .iter()
+                .take(app.config.disks_shown) // Show the configured number of disks
+                .map(|disk| {
+                    let total_space = disk.total_space();
+                    let available_space = disk.available_space();
+                    let used_space = total_space.saturating_sub(available_space);
+                    let usage_percent = if total_space > 0 {
+                        (used_space as f64 / total_space as f64) * 100.0
+                    } else {
+                        0.0
+                    };
 
-                let usage_color = if usage_percent > 90.0 {
-                    Color::Red
-                } else if usage_percent > 75.0 {
-                    Color::Yellow
-                } else {
-                    Color::Green
-                };
+                    let usage_color = if usage_percent > 90.0 {
+                        Color::Red
+                    } else if usage_percent > 75.0 {
+                        Color::Yellow
+                    } else {
+                        Color::Green
+                    };
 
-                disk_lines.push(Line::from(vec![
-                    Span::styled(
-                        format!("{}: ", disk.name().to_string_lossy()),
-                        Style::default().fg(Color::Cyan),
-                    ),
-                    Span::styled(
-                        format!(
-                            "{:.1}GB/{:.1}GB ",
-                            used_space as f64 / 1_073_741_824.0,
-                            total_space as f64 / 1_073_741_824.0
+                    let (read_rate, write_rate) = app
+                        .disk_io_rates
+                        .get(disk.mount_point())
+                        .copied()
+                        .unwrap_or((0.0, 0.0));
+
+                    Row::new(vec![
+                        Span::styled(
+                            disk.name().to_string_lossy().to_string(),
+                            Style::default().fg(Color::Cyan),
                         ),
-                        Style::default().fg(Color::White),
-                    ),
-                    Span::styled(
-                        format!("({:.1}%)", usage_percent),
-                        Style::default().fg(usage_color),
+                        Span::styled(
+                            disk.mount_point().to_string_lossy().to_string(),
+                            Style::default().fg(Color::White),
+                        ),
+                        Span::styled(
+                            format!("{:.1}G", used_space as f64 / 1_073_741_824.0),
+                            Style::default().fg(usage_color),
+                        ),
+                        Span::styled(
+                            format!("{:.1}G", available_space as f64 / 1_073_741_824.0),
+                            Style::default().fg(Color::Green),
+                        ),
+                        Span::styled(
+                            format!("{:.1}G", total_space as f64 / 1_073_741_824.0),
+                            Style::default().fg(Color::White),
+                        ),
+                        Span::styled(format_rate(read_rate), Style::default().fg(Color::Blue)),
+                        Span::styled(
+                            format_rate(write_rate),
+                            Style::default().fg(Color::Magenta),
+                        ),
+                    ])
+                })
+                .collect();
+
+            let disk_table = Table::new(disk_rows, disk_widths)
+                .header(
+                    Row::new(vec!["Disk", "Mount", "Used", "Free", "Total", "R/s", "W/s"]).style(
+                        Style::default()
+                            .fg(Color::Yellow)
+                            .add_modifier(Modifier::BOLD),
                     ),
-                ]));
-            }
+                )
+                .block(
+                    Block::default()
+                        .title("Disk Usage")
+                        .fg(Color::Cyan)
+                        .borders(Borders::ALL),
+                )
+                .column_spacing(1);
 
-            frame.render_widget(
-                Paragraph::new(disk_lines)
-                    .block(
-                        Block::default()
-                            .title("Disk Usage")
-                            .fg(Color::Cyan)
-                            .borders(Borders::ALL),
-                    )
-                    .alignment(Alignment::Left),
-                upper_chunk[1],
-            );
+            frame.render_widget(disk_table, upper_chunk[1]);
 
             frame.render_widget(
                 Paragraph::new(lines)
@@ -233,33 +615,96 @@ fn main() -> Result<()> {
                 upper_chunk[2],
             );
 
-            frame.render_widget(
-                Block::default()
-                    .title("CPU Usage")
-                    .borders(Borders::ALL)
-                    .fg(Color::Green),
-                top_chunk[1],
-            );
+            let average_temp_c = {
+                let readings: Vec<f32> = app.components.iter().filter_map(|c| c.temperature()).collect();
+                if readings.is_empty() {
+                    None
+                } else {
+                    Some(readings.iter().sum::<f32>() / readings.len() as f32)
+                }
+            };
+            let cpu_title = match average_temp_c {
+                Some(celsius) => format!("CPU Usage ({})", format_temp(celsius, app.config.temp_unit)),
+                None => "CPU Usage".to_string(),
+            };
 
             frame.render_widget(
                 Block::default()
                     .title("Processes")
                     .borders(Borders::ALL)
                     .fg(Color::Magenta),
-                main_chunk[1],
+                main_chunk[2],
             );
 
-            let cpus = app.system.cpus();
-            let mut cpu_lines: Vec<Line> = Vec::new();
+            /* Rendering the CPU/Memory/Network history charts */
+
+            app.cpu_history.make_contiguous();
+            app.memory_history.make_contiguous();
+            app.net_rx_history.make_contiguous();
+            app.net_tx_history.make_contiguous();
+
+            let elapsed_secs = app
+                .cpu_history
+                .back()
+                .map(|(x, _)| *x)
+                .unwrap_or(0.0);
+            // Bound the x-axis by the oldest sample actually retained, not by
+            // treating the sample count as if it were seconds — the two only
+            // match when `rate` happens to be 1000ms.
+            let oldest_secs = app.cpu_history.front().map(|(x, _)| *x).unwrap_or(0.0);
+            let x_bounds = [oldest_secs, elapsed_secs.max(oldest_secs + 1.0)];
 
-            cpu_lines.push(Line::from(Span::styled(
-                "CPU Usage",
-                Style::default().add_modifier(Modifier::BOLD),
-            )));
-            cpu_lines.push(Line::from("")); // Add a blank line for spacing
+            frame.render_widget(
+                percent_chart("CPU %", app.cpu_history.as_slices().0, Color::Green, x_bounds),
+                history_chunk[0],
+            );
 
-            for (i, cpu) in cpus.iter().enumerate() {
-                let usage = cpu.cpu_usage();
+            frame.render_widget(
+                percent_chart(
+                    "Memory %",
+                    app.memory_history.as_slices().0,
+                    Color::Yellow,
+                    x_bounds,
+                ),
+                history_chunk[1],
+            );
+
+            let net_max = app
+                .net_rx_history
+                .iter()
+                .chain(app.net_tx_history.iter())
+                .map(|(_, y)| *y)
+                .fold(1.0_f64, f64::max);
+
+            let rx_dataset = Dataset::default()
+                .name("RX/s")
+                .marker(symbols::Marker::Braille)
+                .graph_type(GraphType::Line)
+                .style(Style::default().fg(Color::Cyan))
+                .data(app.net_rx_history.as_slices().0);
+
+            let tx_dataset = Dataset::default()
+                .name("TX/s")
+                .marker(symbols::Marker::Braille)
+                .graph_type(GraphType::Line)
+                .style(Style::default().fg(Color::Magenta))
+                .data(app.net_tx_history.as_slices().0);
+
+            let network_chart = Chart::new(vec![rx_dataset, tx_dataset])
+                .block(
+                    Block::default()
+                        .title("Network (bytes/s)")
+                        .borders(Borders::ALL)
+                        .fg(Color::Cyan),
+                )
+                .x_axis(Axis::default().bounds(x_bounds))
+                .y_axis(Axis::default().bounds([0.0, net_max * 1.2]));
+
+            frame.render_widget(network_chart, history_chunk[2]);
+
+            let cpus = app.system.cpus();
+
+            let cpu_bar_line = |label: String, usage: f32| -> Line {
                 let bar_color = if usage < 30.0 {
                     Color::Green
                 } else if usage < 70.0 {
@@ -274,24 +719,73 @@ fn main() -> Result<()> {
                 let empty_bar_count = bar_width - filled_bar_count;
                 let bar = "█".repeat(filled_bar_count) + &" ".repeat(empty_bar_count);
 
-                // Create a styled line with the CPU name, the bar, and the percentage
-                let line = Line::from(vec![
-                    Span::styled(format!("CPU {:<2}", i), Style::default().fg(Color::White)),
+                Line::from(vec![
+                    Span::styled(format!("{label:<6}"), Style::default().fg(Color::White)),
                     Span::raw(" ["),
                     Span::styled(bar, Style::default().fg(bar_color)),
                     Span::raw("] "),
                     Span::styled(format!("{:.2}%", usage), Style::default().fg(bar_color)),
-                ]);
-                cpu_lines.push(line);
-            }
+                ])
+            };
+
+            let cpu_entries: Vec<(String, f32)> = if app.config.avg_cpu {
+                let average_usage =
+                    cpus.iter().map(|cpu| cpu.cpu_usage()).sum::<f32>() / cpus.len().max(1) as f32;
+                vec![("CPU".to_string(), average_usage)]
+            } else {
+                cpus.iter()
+                    .enumerate()
+                    .map(|(i, cpu)| (format!("CPU {i:<2}"), cpu.cpu_usage()))
+                    .collect()
+            };
+
+            // Wrap per-core bars into as many columns as needed to keep each
+            // column at or under MAX_CPU_ROWS, so high core counts don't clip.
+            let cpu_block = Block::default()
+                .title(cpu_title)
+                .borders(Borders::ALL)
+                .fg(Color::Green);
+            let cpu_inner = cpu_block.inner(top_chunk[1]);
+            frame.render_widget(cpu_block, top_chunk[1]);
+
+            // The "CPU Usage" header is its own row above the columns, so it
+            // doesn't eat into column 0's MAX_CPU_ROWS budget the way every
+            // other column's budget is computed.
+            let cpu_sections = Layout::default()
+                .direction(Direction::Vertical)
+                .constraints([Constraint::Length(2), Constraint::Min(0)])
+                .split(cpu_inner);
 
             frame.render_widget(
-                Paragraph::new(cpu_lines)
-                    .block(Block::default().borders(Borders::ALL).fg(Color::Green)),
-                top_chunk[1],
+                Paragraph::new(vec![
+                    Line::from(Span::styled(
+                        "CPU Usage",
+                        Style::default().add_modifier(Modifier::BOLD),
+                    )),
+                    Line::from(""), // Add a blank line for spacing
+                ]),
+                cpu_sections[0],
             );
 
-            let processes = app.system.processes();
+            let column_count = cpu_entries.len().div_ceil(MAX_CPU_ROWS).max(1);
+            let column_constraints: Vec<Constraint> = (0..column_count)
+                .map(|_| Constraint::Ratio(1, column_count as u32))
+                .collect();
+            let cpu_columns = Layout::default()
+                .direction(Direction::Horizontal)
+                .constraints(column_constraints)
+                .split(cpu_sections[1]);
+
+            for (col_idx, column_area) in cpu_columns.iter().enumerate() {
+                let start = col_idx * MAX_CPU_ROWS;
+                let end = (start + MAX_CPU_ROWS).min(cpu_entries.len());
+                let column_lines: Vec<Line> = cpu_entries[start..end]
+                    .iter()
+                    .map(|(label, usage)| cpu_bar_line(label.clone(), *usage))
+                    .collect();
+
+                frame.render_widget(Paragraph::new(column_lines), *column_area);
+            }
 
             let widths = [
                 Constraint::Length(10), // PID
@@ -300,17 +794,21 @@ fn main() -> Result<()> {
                 Constraint::Length(12), // Memory
             ];
 
-            // Collect and sort processes by CPU usage (highest first)
-            let mut process_list: Vec<_> = processes.iter().collect();
-            process_list.sort_by(|a, b| {
-                b.1.cpu_usage()
-                    .partial_cmp(&a.1.cpu_usage())
-                    .unwrap_or(std::cmp::Ordering::Equal)
-            });
+            // Sort order follows `app.sort_by` / `app.sort_ascending` so it
+            // stays in sync with the `c`/`m` keybindings.
+            let sorted_pids = app.sorted_pids();
+            if app.selected_pid.is_none() {
+                app.selected_pid = sorted_pids.first().copied();
+            }
+            let selected_index = app
+                .selected_pid
+                .and_then(|pid| sorted_pids.iter().position(|p| *p == pid));
+            app.processes_state.select(selected_index);
 
-            let rows: Vec<Row> = process_list
+            let rows: Vec<Row> = sorted_pids
                 .iter()
-                .take(20) // Show top 20 processes by CPU usage
+                .take(app.config.top) // Show the configured number of top processes
+                .filter_map(|pid| app.system.process(*pid).map(|process| (pid, process)))
                 .map(|(pid, process)| {
                     let cpu_usage_raw = process.cpu_usage();
                     // Cap CPU usage at 100% for display (but still sort by actual values)
@@ -361,6 +859,12 @@ fn main() -> Result<()> {
                 })
                 .collect();
 
+            let processes_title = if app.is_frozen {
+                "Processes [FROZEN]"
+            } else {
+                "Processes"
+            };
+
             let process_table = Table::new(rows, widths)
                 .header(
                     Row::new(vec!["PID", "Name", "CPU% (max 100)", "Memory"]).style(
@@ -372,7 +876,7 @@ fn main() -> Result<()> {
                 .block(
                     Block::default()
                         .borders(Borders::ALL)
-                        .title("Processes")
+                        .title(processes_title)
                         .fg(Color::Magenta),
                 )
                 .column_spacing(1)
@@ -382,18 +886,122 @@ fn main() -> Result<()> {
                         .add_modifier(Modifier::BOLD),
                 );
 
-            frame.render_stateful_widget(process_table, main_chunk[1], &mut app.processes_state);
+            frame.render_stateful_widget(process_table, main_chunk[2], &mut app.processes_state);
+
+            if let Some((pid, name)) = &app.pending_kill {
+                let area = centered_rect(40, 20, frame.size());
+                let text = vec![Line::from(vec![Span::raw(format!(
+                    "Kill process {name} ({pid})? (y/n)"
+                ))])];
+                frame.render_widget(Clear, area);
+                frame.render_widget(
+                    Paragraph::new(text)
+                        .alignment(Alignment::Center)
+                        .block(
+                            Block::default()
+                                .title("Confirm Kill")
+                                .borders(Borders::ALL)
+                                .fg(Color::Red),
+                        ),
+                    area,
+                );
+            }
+
+            if app.show_help {
+                let area = centered_rect(60, 60, frame.size());
+                let help_lines = vec![
+                    Line::from(Span::styled(
+                        "General",
+                        Style::default().add_modifier(Modifier::BOLD),
+                    )),
+                    Line::from("  q / Esc      quit"),
+                    Line::from("  ?            toggle this help"),
+                    Line::from(""),
+                    Line::from(Span::styled(
+                        "Processes",
+                        Style::default().add_modifier(Modifier::BOLD),
+                    )),
+                    Line::from("  Up/k, Down/j move selection"),
+                    Line::from("  c            sort by CPU (again to reverse)"),
+                    Line::from("  m            sort by memory (again to reverse)"),
+                    Line::from("  dd           kill selected process (confirm y/n)"),
+                    Line::from("  f            freeze/unfreeze the display"),
+                    Line::from(""),
+                    Line::from(Span::styled(
+                        "Current config",
+                        Style::default().add_modifier(Modifier::BOLD),
+                    )),
+                    Line::from(format!(
+                        "  rate={}ms top={} temp-unit={} avg-cpu={} poll-ms={} disks-shown={}",
+                        app.config.rate,
+                        app.config.top,
+                        app.config.temp_unit,
+                        app.config.avg_cpu,
+                        app.config.poll_ms,
+                        app.config.disks_shown
+                    )),
+                ];
+                frame.render_widget(Clear, area);
+                frame.render_widget(
+                    Paragraph::new(help_lines).block(
+                        Block::default()
+                            .title("Help")
+                            .borders(Borders::ALL)
+                            .fg(Color::Cyan),
+                    ),
+                    area,
+                );
+            }
         })?;
 
         // Handle events with timeout - ignore all non-quit key events
-        if let Ok(true) = event::poll(std::time::Duration::from_millis(16)) {
-            // ~60fps
+        if let Ok(true) = event::poll(std::time::Duration::from_millis(app.config.poll_ms)) {
             if let Ok(evt) = event::read() {
                 match evt {
                     Event::Key(key) => {
-                        match key.code {
-                            KeyCode::Char('q') | KeyCode::Char('Q') | KeyCode::Esc => break,
-                            _ => {} // Ignore all other keys
+                        if app.pending_kill.is_some() {
+                            match key.code {
+                                KeyCode::Char('y') | KeyCode::Char('Y') => app.confirm_kill(),
+                                KeyCode::Char('n') | KeyCode::Char('N') | KeyCode::Esc => {
+                                    app.cancel_kill()
+                                }
+                                _ => {}
+                            }
+                        } else if app.show_help {
+                            match key.code {
+                                KeyCode::Char('?') | KeyCode::Esc => app.show_help = false,
+                                _ => {}
+                            }
+                        } else {
+                            // Any key other than `d` breaks the `dd` combo,
+                            // so `d`, <move>, `d` doesn't count as a double-press.
+                            if !matches!(key.code, KeyCode::Char('d')) {
+                                app.last_key = None;
+                            }
+                            match key.code {
+                                KeyCode::Char('q') | KeyCode::Char('Q') | KeyCode::Esc => break,
+                                KeyCode::Char('?') => app.show_help = true,
+                                KeyCode::Up | KeyCode::Char('k') => app.select_previous(),
+                                KeyCode::Down | KeyCode::Char('j') => app.select_next(),
+                                KeyCode::Char('c') => app.toggle_sort(SortBy::Cpu),
+                                KeyCode::Char('m') => app.toggle_sort(SortBy::Memory),
+                                KeyCode::Char('f') => app.is_frozen = !app.is_frozen,
+                                KeyCode::Char('d') => {
+                                    let now = Instant::now();
+                                    let is_double = matches!(
+                                        app.last_key,
+                                        Some((KeyCode::Char('d'), at))
+                                            if now.duration_since(at) <= DOUBLE_KEY_TIMEOUT
+                                    );
+                                    if is_double {
+                                        app.request_kill_selected();
+                                        app.last_key = None;
+                                    } else {
+                                        app.last_key = Some((KeyCode::Char('d'), now));
+                                    }
+                                }
+                                _ => {} // Ignore all other keys
+                            }
                         }
                     }
                     // Silently ignore ALL other events (mouse, scroll, resize, etc)
@@ -403,7 +1011,7 @@ fn main() -> Result<()> {
         }
 
         // Control update frequency
-        std::thread::sleep(std::time::Duration::from_millis(200));
+        std::thread::sleep(std::time::Duration::from_millis(app.config.rate));
     }
 
     stdout().execute(LeaveAlternateScreen)?;